@@ -29,6 +29,7 @@ struct Post {
     content: Content,
     parsed: Vec<markdown::Item>,
     name: String,
+    slug: String,
     description: String,
     tags: String,
     image_url: String,
@@ -39,6 +40,10 @@ struct Post {
     time: Time,
     show_picker_time: bool,
     image: Option<Handle>,
+    library: Vec<(PathBuf, BlogPost)>,
+    library_dir: Option<PathBuf>,
+    tag_filter: String,
+    sort_newest: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -49,6 +54,26 @@ struct BlogPost {
     summary: String,
     timestamp: DateTime<Utc>,
     tags: Vec<String>,
+    #[serde(default)]
+    slug: String,
+}
+
+/// Transliterate Unicode to ASCII, lowercase, and collapse any run of
+/// non-alphanumerics into single hyphens, trimming the ends.
+fn slugify(input: &str) -> String {
+    let ascii = deunicode::deunicode(input).to_lowercase();
+    let mut slug = String::new();
+    let mut prev_hyphen = false;
+    for c in ascii.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+            prev_hyphen = false;
+        } else if !prev_hyphen {
+            slug.push('-');
+            prev_hyphen = true;
+        }
+    }
+    slug.trim_matches('-').to_string()
 }
 
 impl From<BlogPost> for Post {
@@ -56,6 +81,11 @@ impl From<BlogPost> for Post {
         Self {
             content: Content::with_text(&post.body),
             parsed: vec![],
+            slug: if post.slug.is_empty() {
+                slugify(&post.title)
+            } else {
+                post.slug
+            },
             name: post.title,
             description: post.summary,
             tags: post.tags.join(","),
@@ -76,6 +106,10 @@ impl From<BlogPost> for Post {
             },
             show_picker_time: false,
             image: None,
+            library: vec![],
+            library_dir: None,
+            tag_filter: String::new(),
+            sort_newest: true,
         }
     }
 }
@@ -115,6 +149,7 @@ impl From<Post> for BlogPost {
                 )
                 .unwrap(),
             tags: post.tags.split(",").map(|v| v.trim().to_string()).collect(),
+            slug: post.slug,
         }
     }
 }
@@ -154,6 +189,7 @@ impl From<&Post> for BlogPost {
                 )
                 .unwrap(),
             tags: post.tags.split(",").map(|v| v.trim().to_string()).collect(),
+            slug: post.slug.clone(),
         }
     }
 }
@@ -163,6 +199,7 @@ enum TabID {
     #[default]
     Content,
     Meta,
+    Library,
 }
 
 #[derive(Debug, Clone)]
@@ -170,11 +207,19 @@ enum Message {
     LinkClicked(markdown::Url),
     EditContent(Action),
     EditTitle(String),
+    EditSlug(String),
     EditSummary(String),
     EditTags(String),
     EditImageUrl(String),
     SubmitImageUrl(String),
     TabSelected(TabID),
+    ExportHtml,
+    BuildFeed,
+    OpenLibrary,
+    EditTagFilter(String),
+    ClearTags,
+    ToggleSort,
+    SelectPost(PathBuf),
     LoadFile,
     SaveFile,
     SaveToFile,
@@ -186,13 +231,77 @@ enum Message {
     CancelTime,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct FrontMatter {
+    title: String,
+    summary: String,
+    image_url: String,
+    tags: Vec<String>,
+    timestamp: DateTime<Utc>,
+    #[serde(default)]
+    slug: String,
+}
+
+fn load_markdown(text: &str) -> Option<BlogPost> {
+    // The front-matter is delimited by lines that are exactly `---`, so match
+    // whole lines rather than any substring that merely starts with dashes.
+    let mut lines = text.trim_start().lines();
+    if lines.next()?.trim_end() != "---" {
+        return None;
+    }
+    let mut yaml = String::new();
+    let mut closed = false;
+    for line in lines.by_ref() {
+        if line.trim_end() == "---" {
+            closed = true;
+            break;
+        }
+        yaml.push_str(line);
+        yaml.push('\n');
+    }
+    if !closed {
+        return None;
+    }
+    let body = lines.collect::<Vec<_>>().join("\n");
+    let fm: FrontMatter = serde_yaml::from_str(&yaml).ok()?;
+    Some(BlogPost {
+        title: fm.title,
+        body,
+        image_url: fm.image_url,
+        summary: fm.summary,
+        timestamp: fm.timestamp,
+        tags: fm.tags,
+        slug: fm.slug,
+    })
+}
+
+fn save_markdown(post: &BlogPost) -> String {
+    let fm = FrontMatter {
+        title: post.title.clone(),
+        summary: post.summary.clone(),
+        image_url: post.image_url.clone(),
+        tags: post.tags.clone(),
+        timestamp: post.timestamp,
+        slug: post.slug.clone(),
+    };
+    let yaml = serde_yaml::to_string(&fm).unwrap_or_default();
+    format!("---\n{}---\n{}", yaml, post.body)
+}
+
 fn load_from_file(path: &PathBuf) -> Post {
     println!("{}", path.to_str().unwrap());
-    let fileres = File::open(path);
-    match fileres {
-        Ok(file) => serde_json::from_reader::<_, BlogPost>(BufReader::new(file))
-            .unwrap()
-            .into(),
+    let Ok(text) = std::fs::read_to_string(path) else {
+        return Post::default();
+    };
+    let is_md = path.extension().and_then(|e| e.to_str()) == Some("md")
+        || text.trim_start().starts_with("---");
+    if is_md {
+        if let Some(post) = load_markdown(&text) {
+            return post.into();
+        }
+    }
+    match serde_json::from_str::<BlogPost>(&text) {
+        Ok(post) => post.into(),
         Err(_) => Post::default(),
     }
 }
@@ -202,34 +311,314 @@ fn select_file() -> Option<PathBuf> {
         .set_directory("~/Documents/")
         .set_title("Select Post")
         .add_filter("json", &["json"])
+        .add_filter("markdown", &["md"])
         .pick_file()
 }
 
-fn save_file(post_name: &str) -> Option<PathBuf> {
+fn save_file(slug: &str) -> Option<PathBuf> {
     rfd::FileDialog::new()
         .set_directory("~/Documents/")
         .set_title("Select Post Save Location")
-        .set_file_name(post_name.to_lowercase().replace(" ", "-"))
+        .set_file_name(slug)
         .add_filter("json", &["json"])
+        .add_filter("markdown", &["md"])
         .set_can_create_directories(true)
         .save_file()
 }
 
-fn fetch_image(url: String) -> Result<Handle, String> {
+fn store_image(url: &str, base: Option<&std::path::Path>) -> Result<(Handle, String), String> {
     let resp = reqwest::blocking::get(url).map_err(|e| e.to_string())?;
+    let bytes = resp.bytes().map_err(|e| e.to_string())?.to_vec();
+    let img = image::load_from_memory(&bytes).map_err(|e| e.to_string())?;
+    let ext = image::guess_format(&bytes)
+        .ok()
+        .and_then(|f| f.extensions_str().first().copied())
+        .unwrap_or("bin");
+    let hash = etag(&bytes);
+
+    // Resolve `assets/` next to where the post is saved so the relative
+    // reference stays valid wherever the static site is generated.
+    let dir = base.unwrap_or_else(|| std::path::Path::new(".")).join("assets");
+    let _ = std::fs::create_dir_all(&dir);
+
+    let original = dir.join(format!("{hash}.{ext}"));
+    if !original.exists() {
+        let _ = std::fs::write(&original, &bytes);
+    }
+    let thumb = dir.join(format!("{hash}.thumb.jpg"));
+    if !thumb.exists() {
+        let _ = img.thumbnail(400, 400).to_rgb8().save(&thumb);
+    }
+    Ok((Handle::from_bytes(bytes), format!("assets/{hash}.{ext}")))
+}
+
+const DEFAULT_HTML_TEMPLATE: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<title>{{title}}</title>
+<meta name="description" content="{{summary}}">
+<link rel="stylesheet" href="{{css}}">
+</head>
+<body>
+<article>
+<h1>{{title}}</h1>
+<p class="summary">{{summary}}</p>
+<time datetime="{{timestamp}}">{{timestamp}}</time>
+{{#if image_url}}<img src="{{image_url}}" alt="{{title}}">{{/if}}
+<div class="body">{{{body}}}</div>
+<ul class="tags">{{#each tags}}<li>{{this}}</li>{{/each}}</ul>
+</article>
+</body>
+</html>
+"#;
+
+const DEFAULT_CSS: &str = r#"body { max-width: 42rem; margin: 2rem auto; padding: 0 1rem; font-family: system-ui, sans-serif; line-height: 1.6; }
+h1 { line-height: 1.2; }
+.summary { color: #555; font-style: italic; }
+time { display: block; color: #888; font-size: 0.9rem; }
+img { max-width: 100%; height: auto; }
+blockquote { margin: 1rem 0; padding: 0.5rem 1rem; border-left: 4px solid #ccc; color: #555; }
+code { background: #f4f4f4; padding: 0.1rem 0.3rem; border-radius: 3px; }
+pre { background: #f4f4f4; padding: 1rem; overflow-x: auto; border-radius: 4px; }
+pre code { background: none; padding: 0; }
+.tags { list-style: none; padding: 0; display: flex; gap: 0.5rem; flex-wrap: wrap; }
+.tags li { background: #eee; padding: 0.1rem 0.5rem; border-radius: 3px; font-size: 0.85rem; }
+"#;
+
+fn render_markdown(text: &str) -> String {
+    let mut options = comrak::ComrakOptions::default();
+    options.extension.table = true;
+    options.extension.footnotes = true;
+    options.extension.strikethrough = true;
+    options.extension.autolink = true;
+    comrak::markdown_to_html(text, &options)
+}
+
+fn templates_dir() -> PathBuf {
+    std::env::var_os("BLOG_TEMPLATES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("templates"))
+}
+
+fn load_template() -> String {
+    std::fs::read_to_string(templates_dir().join("post.hbs"))
+        .unwrap_or_else(|_| DEFAULT_HTML_TEMPLATE.to_string())
+}
+
+fn load_css() -> String {
+    std::fs::read_to_string(templates_dir().join("style.css"))
+        .unwrap_or_else(|_| DEFAULT_CSS.to_string())
+}
+
+fn export_file(slug: &str) -> Option<PathBuf> {
+    rfd::FileDialog::new()
+        .set_directory("~/Documents/")
+        .set_title("Export Post as HTML")
+        .set_file_name(format!("{slug}.html"))
+        .add_filter("html", &["html"])
+        .set_can_create_directories(true)
+        .save_file()
+}
+
+fn export_html(state: &Post, path: &PathBuf) {
+    let post = BlogPost::from(state);
+    let body = render_markdown(&post.body);
+    let data = serde_json::json!({
+        "title": post.title,
+        "summary": post.summary,
+        "image_url": post.image_url,
+        "body": body,
+        "timestamp": post.timestamp.format("%B %-d, %Y").to_string(),
+        "tags": post.tags,
+        "css": "style.css",
+    });
+    let rendered = handlebars::Handlebars::new().render_template(&load_template(), &data);
+    if let Ok(rendered) = rendered {
+        if let Ok(mut file) = File::create(path) {
+            let _ = file.write_all(rendered.as_bytes());
+        }
+        // Write the stylesheet the template links to, next to the page.
+        let css_path = path
+            .parent()
+            .unwrap_or_else(|| std::path::Path::new("."))
+            .join("style.css");
+        if !css_path.exists() {
+            let _ = std::fs::write(css_path, load_css());
+        }
+    }
+}
+
+fn select_dir() -> Option<PathBuf> {
+    rfd::FileDialog::new()
+        .set_directory("~/Documents/")
+        .set_title("Select Posts Directory")
+        .pick_folder()
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
 
-    let bytes = resp.bytes().map_err(|e| e.to_string())?;
-    Ok(Handle::from_bytes(bytes.to_vec()))
+fn etag(bytes: &[u8]) -> String {
+    use blake2::{Blake2b512, Digest};
+    let mut hasher = Blake2b512::new();
+    hasher.update(bytes);
+    format!("{:x}", hasher.finalize())
+}
+
+fn load_posts_dir(dir: &PathBuf) -> Vec<(String, BlogPost)> {
+    // Scan both persistence formats the app writes (JSON and Markdown),
+    // reusing the same dual-load logic the library view uses.
+    let mut posts: Vec<(String, BlogPost)> = load_library(dir)
+        .into_iter()
+        .map(|(path, post)| {
+            let slug = if post.slug.is_empty() {
+                path.file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or("post")
+                    .to_string()
+            } else {
+                post.slug.clone()
+            };
+            (slug, post)
+        })
+        .collect();
+    posts.sort_by(|a, b| b.1.timestamp.cmp(&a.1.timestamp));
+    posts.truncate(20);
+    posts
+}
+
+fn build_rss(posts: &[(String, BlogPost)]) -> String {
+    let mut items = String::new();
+    for (slug, post) in posts {
+        let categories: String = post
+            .tags
+            .iter()
+            .map(|t| format!("<category>{}</category>", xml_escape(t)))
+            .collect();
+        items.push_str(&format!(
+            "<item><title>{}</title><description>{}</description><content:encoded><![CDATA[{}]]></content:encoded><pubDate>{}</pubDate><guid isPermaLink=\"false\">{}</guid>{}</item>",
+            xml_escape(&post.title),
+            xml_escape(&post.summary),
+            render_markdown(&post.body),
+            post.timestamp.to_rfc2822(),
+            xml_escape(slug),
+            categories,
+        ));
+    }
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<rss version=\"2.0\" xmlns:content=\"http://purl.org/rss/1.0/modules/content/\"><channel><title>Blog</title>{items}</channel></rss>\n"
+    )
+}
+
+fn build_atom(posts: &[(String, BlogPost)], feed_id: &str) -> String {
+    let mut entries = String::new();
+    for (slug, post) in posts {
+        let categories: String = post
+            .tags
+            .iter()
+            .map(|t| format!("<category term=\"{}\"/>", xml_escape(t)))
+            .collect();
+        entries.push_str(&format!(
+            "<entry><title>{}</title><id>{}</id><updated>{}</updated><summary>{}</summary><content type=\"html\"><![CDATA[{}]]></content>{}</entry>",
+            xml_escape(&post.title),
+            xml_escape(slug),
+            post.timestamp.to_rfc3339(),
+            xml_escape(&post.summary),
+            render_markdown(&post.body),
+            categories,
+        ));
+    }
+    let updated = posts
+        .first()
+        .map(|(_, p)| p.timestamp.to_rfc3339())
+        .unwrap_or_default();
+    format!(
+        "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<feed xmlns=\"http://www.w3.org/2005/Atom\"><title>Blog</title><id>{}</id><updated>{updated}</updated>{entries}</feed>\n",
+        xml_escape(feed_id)
+    )
+}
+
+fn build_json_feed(posts: &[(String, BlogPost)]) -> String {
+    let items: Vec<_> = posts
+        .iter()
+        .map(|(slug, post)| {
+            serde_json::json!({
+                "id": slug,
+                "title": post.title,
+                "summary": post.summary,
+                "content_html": render_markdown(&post.body),
+                "date_published": post.timestamp.to_rfc3339(),
+                "tags": post.tags,
+            })
+        })
+        .collect();
+    serde_json::to_string_pretty(&serde_json::json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": "Blog",
+        "items": items,
+    }))
+    .unwrap_or_default()
+}
+
+fn load_library(dir: &PathBuf) -> Vec<(PathBuf, BlogPost)> {
+    let mut posts = vec![];
+    if let Ok(entries) = std::fs::read_dir(dir) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let ext = path.extension().and_then(|e| e.to_str());
+            let loaded = match ext {
+                Some("md") => std::fs::read_to_string(&path)
+                    .ok()
+                    .and_then(|t| load_markdown(&t)),
+                Some("json") => File::open(&path)
+                    .ok()
+                    .and_then(|f| serde_json::from_reader::<_, BlogPost>(BufReader::new(f)).ok()),
+                _ => None,
+            };
+            if let Some(post) = loaded {
+                posts.push((path, post));
+            }
+        }
+    }
+    posts
+}
+
+fn build_feed(dir: &PathBuf) {
+    let posts = load_posts_dir(dir);
+    // A stable feed id: the configured site URL, else a URN for the directory.
+    let feed_id = std::env::var("BLOG_SITE_URL")
+        .unwrap_or_else(|_| format!("urn:blog:{}", dir.display()));
+    let rss = build_rss(&posts);
+    let atom = build_atom(&posts, &feed_id);
+    let json = build_json_feed(&posts);
+    let tag = etag(format!("{rss}{atom}{json}").as_bytes());
+    let etag_path = dir.join("feed.etag");
+    if std::fs::read_to_string(&etag_path)
+        .map(|e| e == tag)
+        .unwrap_or(false)
+    {
+        return;
+    }
+    let _ = std::fs::write(dir.join("feed.xml"), rss);
+    let _ = std::fs::write(dir.join("atom.xml"), atom);
+    let _ = std::fs::write(dir.join("feed.json"), json);
+    let _ = std::fs::write(etag_path, tag);
 }
 
 fn save_to_file(path: &PathBuf, state: &Post) {
-    let fileres = File::create(path);
-    if let Ok(mut file) = fileres {
-        let _ = file.write_all(
-            serde_json::to_string(&BlogPost::from(state))
-                .unwrap_or_default()
-                .as_bytes(),
-        );
+    let post = BlogPost::from(state);
+    let contents = if path.extension().and_then(|e| e.to_str()) == Some("md") {
+        save_markdown(&post)
+    } else {
+        serde_json::to_string(&post).unwrap_or_default()
+    };
+    if let Ok(mut file) = File::create(path) {
+        let _ = file.write_all(contents.as_bytes());
     }
 }
 
@@ -278,6 +667,10 @@ fn meta_view(state: &Post) -> Element<'_, Message> {
     .use_24h()
     .show_seconds();
 
+    let slug: Element<'_, Message> = text_input("how-to-cook-bread", &state.slug)
+        .on_input(Message::EditSlug)
+        .into();
+
     let summary: Element<'_, Message> = text_input(
         "How to cook bread in just three easy steps...",
         &state.description,
@@ -295,6 +688,8 @@ fn meta_view(state: &Post) -> Element<'_, Message> {
         .into();
 
     let interface = column![
+        text("Slug"),
+        slug,
         text("Summary"),
         summary,
         text("Tags (seperated by ,'s)"),
@@ -313,7 +708,67 @@ fn meta_view(state: &Post) -> Element<'_, Message> {
     }
 }
 
+fn library_view(state: &Post) -> Element<'_, Message> {
+    let filter = state.tag_filter.trim().to_lowercase();
+    let mut entries: Vec<&(PathBuf, BlogPost)> = state
+        .library
+        .iter()
+        .filter(|(_, p)| {
+            filter.is_empty() || p.tags.iter().any(|t| t.to_lowercase().contains(&filter))
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        if state.sort_newest {
+            b.1.timestamp.cmp(&a.1.timestamp)
+        } else {
+            a.1.timestamp.cmp(&b.1.timestamp)
+        }
+    });
+
+    let mut list = column![];
+    for (path, post) in entries {
+        let label = format!(
+            "{}  ·  {}  ·  {}",
+            post.title,
+            post.timestamp.format("%Y-%m-%d"),
+            post.tags.join(", ")
+        );
+        let entry: Element<'_, Message> = Button::new(text(label))
+            .on_press(Message::SelectPost(path.clone()))
+            .into();
+        list = list.push(entry);
+    }
+
+    let open: Element<'_, Message> = Button::new(text("Open Folder"))
+        .on_press(Message::OpenLibrary)
+        .into();
+    let tag_input: Element<'_, Message> = text_input("filter by tag", &state.tag_filter)
+        .on_input(Message::EditTagFilter)
+        .into();
+    let clear: Element<'_, Message> = Button::new(text("clear tags"))
+        .on_press(Message::ClearTags)
+        .into();
+    let sort: Element<'_, Message> = Button::new(text(if state.sort_newest {
+        "newest first"
+    } else {
+        "oldest first"
+    }))
+    .on_press(Message::ToggleSort)
+    .into();
+
+    column![row![open, tag_input, clear, sort], list].into()
+}
+
 impl Post {
+    /// The slug to use for filenames, falling back to one derived from the title.
+    fn file_slug(&self) -> String {
+        if self.slug.is_empty() {
+            slugify(&self.name)
+        } else {
+            self.slug.clone()
+        }
+    }
+
     fn update(&mut self, message: Message) {
         match message {
             Message::LinkClicked(url) => {
@@ -325,17 +780,27 @@ impl Post {
             Message::TabSelected(id) => {
                 self.selected_tab = id;
             }
-            Message::EditTitle(title) => self.name = title,
+            Message::EditTitle(title) => {
+                // Keep the slug in sync with the title until the user edits it by hand.
+                if self.slug == slugify(&self.name) {
+                    self.slug = slugify(&title);
+                }
+                self.name = title;
+            }
+            Message::EditSlug(slug) => self.slug = slug,
             Message::EditSummary(summary) => self.description = summary,
             Message::EditTags(tags) => self.tags = tags,
             Message::EditImageUrl(url) => self.image_url = url,
-            Message::SubmitImageUrl(url) => {
-                let image = fetch_image(url);
-                match image {
-                    Ok(handle) => self.image = Some(handle),
-                    Err(_) => self.image = None,
+            Message::SubmitImageUrl(url) => match store_image(
+                &url,
+                self.savepath.as_deref().and_then(|p| p.parent()),
+            ) {
+                Ok((handle, rel)) => {
+                    self.image = Some(handle);
+                    self.image_url = rel;
                 }
-            }
+                Err(_) => self.image = None,
+            },
             Message::ChooseDate => {
                 self.show_picker = true;
             }
@@ -356,6 +821,35 @@ impl Post {
             Message::CancelTime => {
                 self.show_picker_time = false;
             }
+            Message::ExportHtml => {
+                if let Some(path) = export_file(&self.file_slug()) {
+                    export_html(self, &path);
+                }
+            }
+            Message::BuildFeed => {
+                if let Some(dir) = select_dir() {
+                    build_feed(&dir);
+                }
+            }
+            Message::OpenLibrary => {
+                if let Some(dir) = select_dir() {
+                    self.library = load_library(&dir);
+                    self.library_dir = Some(dir);
+                }
+            }
+            Message::EditTagFilter(filter) => self.tag_filter = filter,
+            Message::ClearTags => self.tag_filter.clear(),
+            Message::ToggleSort => self.sort_newest = !self.sort_newest,
+            Message::SelectPost(path) => {
+                let mut new_state = load_from_file(&path);
+                new_state.savepath = Some(path);
+                new_state.selected_tab = self.selected_tab.clone();
+                new_state.library = std::mem::take(&mut self.library);
+                new_state.library_dir = self.library_dir.take();
+                new_state.tag_filter = std::mem::take(&mut self.tag_filter);
+                new_state.sort_newest = self.sort_newest;
+                *self = new_state;
+            }
             Message::LoadFile => {
                 let path = select_file();
                 if path.is_some() {
@@ -367,7 +861,7 @@ impl Post {
             }
             Message::SaveFile => {
                 if self.savepath.is_none() {
-                    let path = save_file(&self.name);
+                    let path = save_file(&self.file_slug());
                     if path.is_some() {
                         self.savepath = path;
                     }
@@ -375,12 +869,18 @@ impl Post {
                 if self.savepath.is_some() {
                     save_to_file(&self.savepath.clone().unwrap(), self);
                 }
+                if let Some(dir) = self.library_dir.clone() {
+                    self.library = load_library(&dir);
+                }
             }
             Message::SaveToFile => {
-                self.savepath = save_file(&self.name);
+                self.savepath = save_file(&self.file_slug());
                 if self.savepath.is_some() {
                     save_to_file(&self.savepath.clone().unwrap(), self);
                 }
+                if let Some(dir) = self.library_dir.clone() {
+                    self.library = load_library(&dir);
+                }
             } //_ => {}
         }
         self.parsed = markdown::parse(&self.content.text()).collect();
@@ -397,6 +897,11 @@ impl Post {
                 iced_aw::TabLabel::Text("Meta".to_string()),
                 container(meta_view(self)).height(Length::Fill),
             )
+            .push(
+                TabID::Library,
+                iced_aw::TabLabel::Text("Library".to_string()),
+                container(library_view(self)).height(Length::Fill),
+            )
             .set_active_tab(&self.selected_tab)
             .tab_bar_position(TabBarPosition::Bottom)
             .into()
@@ -409,6 +914,7 @@ impl Default for Post {
             content: Content::default(),
             parsed: vec![],
             name: "".to_string(),
+            slug: "".to_string(),
             description: "".to_string(),
             tags: "".to_string(),
             image_url: "".to_string(),
@@ -419,6 +925,10 @@ impl Default for Post {
             time: Time::now_hms(true),
             show_picker_time: false,
             image: None,
+            library: vec![],
+            library_dir: None,
+            tag_filter: String::new(),
+            sort_newest: true,
         }
     }
 }
@@ -429,6 +939,7 @@ fn subscription(_: &Post) -> Subscription<Message> {
             (Key::Character("s"), m) if m.command() && m.shift() => Some(Message::SaveToFile),
             (Key::Character("s"), m) if m.command() => Some(Message::SaveFile),
             (Key::Character("o"), m) if m.command() => Some(Message::LoadFile),
+            (Key::Character("e"), m) if m.command() => Some(Message::ExportHtml),
             _ => None,
         },
     )